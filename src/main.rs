@@ -1,6 +1,50 @@
 use crossterm::{cursor, event, style, terminal, QueueableCommand};
 use num::complex::Complex;
+use rug::Float;
 use std::io::{stdout, Stdout, Write};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const CHUNK_HEIGHT: usize = 4;
+const PROGRESSIVE_STRIDES: [usize; 4] = [8, 4, 2, 1];
+
+// Below this view width, neighbouring pixels are closer together than f64
+// can resolve (~1e-15 relative), so `x_min + pixel * delta` starts aliasing
+// several pixels onto the same float. Switch to perturbation-theory
+// evaluation once the view shrinks past this floor.
+const DEEP_ZOOM_THRESHOLD: f64 = 1e-13;
+
+// Bits of mantissa for the arbitrary-precision reference orbit. Comfortably
+// covers zooms many orders of magnitude past the f64 floor.
+const PERTURBATION_PRECISION_BITS: u32 = 256;
+
+// A delta orbit has "glitched" once it grows to dominate the true orbit
+// value it's approximating; below this ratio the approximation is no
+// longer trustworthy.
+const GLITCH_EPSILON: f64 = 1e-6;
+
+const AUTO_ITERATION_BASE: u16 = 50;
+const AUTO_ITERATION_SCALE: f64 = 80.0;
+const AUTO_ITERATION_MAX: u16 = 20000;
+
+// Scales `max_iterations` with zoom depth: detail that's only visible deep
+// in the set needs more iterations to resolve, while a zoomed-out view
+// would just waste time computing iterations nobody can see.
+fn auto_max_iterations(x_size: f64) -> u16 {
+    let depth = -(x_size.abs().log10());
+    let scaled = AUTO_ITERATION_BASE as f64 + (depth.max(0.0) * AUTO_ITERATION_SCALE);
+    return (scaled as u16).clamp(AUTO_ITERATION_BASE, AUTO_ITERATION_MAX);
+}
+
+#[derive(Clone, Copy)]
+struct ViewRect {
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+}
 
 fn lerp(perc: f64, low: u8, high: u8) -> u8 {
     let percentage: f64 = if perc < 1.0 {
@@ -18,7 +62,11 @@ fn lerp(perc: f64, low: u8, high: u8) -> u8 {
     return result as u8;
 }
 
-fn scale_color(value: u16, max_value: u16) -> (u8, u8, u8) {
+// Escape radius for the bailout test, raised from the textbook 2.0 so that
+// `ln(ln(|z|))` in `smooth_iteration_count` stays well-behaved just past escape.
+const ESCAPE_RADIUS: f64 = 4.0;
+
+fn scale_color(value: f64, max_value: f64) -> (u8, u8, u8) {
     let none_color = (0, 0, 0);
     let colors = [
         [13, 0, 51],
@@ -28,10 +76,10 @@ fn scale_color(value: u16, max_value: u16) -> (u8, u8, u8) {
         [240, 120, 140],
     ];
 
-    if value == 0 {
+    if value == 0.0 {
         return none_color;
     }
-    let percentage: f64 = value as f64 / max_value as f64;
+    let percentage: f64 = value / max_value;
     let low_index = (percentage * ((colors.len() - 2) as f64)) as usize;
     return (
         lerp(percentage, colors[low_index][0], colors[low_index + 1][0]),
@@ -40,18 +88,198 @@ fn scale_color(value: u16, max_value: u16) -> (u8, u8, u8) {
     );
 }
 
-fn calculate_instability(c: Complex<f64>, max_iterations: u16) -> u16 {
+// Returns the raw escape iteration (0 if the point never escapes) alongside
+// the final `|z|`, so callers can derive a continuous iteration count.
+fn calculate_instability(c: Complex<f64>, max_iterations: u16) -> (u16, f64) {
     let mut prev_z = Complex::new(0.0, 0.0);
     for iteration in 1..=max_iterations {
         prev_z = (prev_z * prev_z) + c;
-        if prev_z.norm() > 2.0 {
-            return iteration;
+        let norm = prev_z.norm();
+        if norm > ESCAPE_RADIUS {
+            return (iteration, norm);
         }
     }
-    return 0;
+    return (0, 0.0);
 }
 
-fn generate_mandelbrot(
+// Normalized ("smooth") iteration count: turns the banded integer escape
+// count into a continuous value so palette interpolation has no visible
+// contour lines. Points that never escape keep the "inside" value of 0.0.
+fn smooth_iteration_count(iteration: u16, escaped_norm: f64) -> f64 {
+    if iteration == 0 {
+        return 0.0;
+    }
+    return (iteration as f64) + 1.0 - (escaped_norm.ln().ln() / 2.0f64.ln());
+}
+
+// A high-precision orbit for one reference point (the view centre),
+// pre-computed once per frame. Pixels evaluate their *delta* from this
+// orbit in cheap f64, only falling back to full precision when that delta
+// approximation glitches or outlives the reference orbit. `center_r`/`center_i` are kept at full precision
+// (not just the f64 orbit entries) so a glitched pixel can be re-centred
+// without losing its tiny `delta_c` offset to rounding.
+struct PerturbationReference {
+    center_r: Float,
+    center_i: Float,
+    orbit: Vec<Complex<f64>>,
+    max_iterations: u16,
+    // Iteration at which the reference orbit itself escaped, if it did
+    // before `max_iterations`. The orbit stops growing past this point, so
+    // pixels that outlive it have no more reference data to delta against.
+    escape_iteration: Option<u16>,
+}
+
+fn compute_reference_orbit(center_r: Float, center_i: Float, max_iterations: u16) -> PerturbationReference {
+    let mut zr = Float::with_val(PERTURBATION_PRECISION_BITS, 0.0);
+    let mut zi = Float::with_val(PERTURBATION_PRECISION_BITS, 0.0);
+
+    // orbit[k] holds Z_k uniformly, starting from Z_0 = 0, so the delta
+    // recurrence in `calculate_instability_delta` can index it the same
+    // way `calculate_instability` indexes its own iteration count.
+    let mut orbit = Vec::with_capacity(max_iterations as usize + 1);
+    orbit.push(Complex::new(0.0, 0.0));
+
+    let mut escape_iteration = None;
+    for iteration in 1..=max_iterations {
+        let zr2 = Float::with_val(PERTURBATION_PRECISION_BITS, &zr * &zr);
+        let zi2 = Float::with_val(PERTURBATION_PRECISION_BITS, &zi * &zi);
+        let cross = Float::with_val(PERTURBATION_PRECISION_BITS, &zr * &zi);
+
+        let next_zr = Float::with_val(PERTURBATION_PRECISION_BITS, &zr2 - &zi2) + &center_r;
+        let next_zi = Float::with_val(PERTURBATION_PRECISION_BITS, &cross * 2) + &center_i;
+
+        zr = next_zr;
+        zi = next_zi;
+
+        orbit.push(Complex::new(zr.to_f64(), zi.to_f64()));
+
+        // Bail out as soon as the reference itself escapes instead of
+        // always running the full `max_iterations` arbitrary-precision
+        // squarings: most deep-zoom reference points are near the set
+        // boundary, but not every one of them, and this recurrence runs
+        // synchronously before any pixel reaches the worker pool.
+        if (zr.to_f64().powi(2) + zi.to_f64().powi(2)) > ESCAPE_RADIUS * ESCAPE_RADIUS {
+            escape_iteration = Some(iteration);
+            break;
+        }
+    }
+
+    return PerturbationReference {
+        center_r,
+        center_i,
+        orbit,
+        max_iterations,
+        escape_iteration,
+    };
+}
+
+// Re-centres a fresh reference orbit exactly on a glitched pixel (at full
+// arbitrary precision, so `delta_c` isn't rounded away the way plain f64
+// addition would) and reads the pixel's own escape directly off it, since
+// a reference centred on the pixel *is* the pixel's orbit.
+fn recompute_from_new_reference(delta_c: Complex<f64>, reference: &PerturbationReference) -> (u16, f64) {
+    let center_r = Float::with_val(PERTURBATION_PRECISION_BITS, &reference.center_r + delta_c.re);
+    let center_i = Float::with_val(PERTURBATION_PRECISION_BITS, &reference.center_i + delta_c.im);
+    let new_reference = compute_reference_orbit(center_r, center_i, reference.max_iterations);
+
+    return match new_reference.escape_iteration {
+        Some(iteration) => (iteration, new_reference.orbit[iteration as usize].norm()),
+        None => (0, 0.0),
+    };
+}
+
+// Perturbation-theory counterpart to `calculate_instability`: evaluates a
+// pixel as its delta `delta_c` from the reference orbit's centre instead of
+// as an absolute coordinate, so the recurrence stays accurate in f64 long
+// after the absolute coordinate itself would have collapsed onto its
+// neighbours. Re-centres a fresh reference on the pixel itself when the
+// delta orbit glitches or outlives the reference orbit.
+fn calculate_instability_delta(
+    delta_c: Complex<f64>,
+    reference: &PerturbationReference,
+) -> (u16, f64) {
+    let available = reference.orbit.len() - 1;
+    let mut delta_z = Complex::new(0.0, 0.0);
+    for iteration in 1..=reference.max_iterations {
+        if (iteration as usize) > available {
+            return recompute_from_new_reference(delta_c, reference);
+        }
+
+        let ref_z = reference.orbit[(iteration - 1) as usize];
+        delta_z = (ref_z * 2.0 * delta_z) + (delta_z * delta_z) + delta_c;
+
+        let full_z = reference.orbit[iteration as usize] + delta_z;
+        let full_norm = full_z.norm();
+
+        if full_norm < delta_z.norm() * GLITCH_EPSILON {
+            return recompute_from_new_reference(delta_c, reference);
+        }
+
+        if full_norm > ESCAPE_RADIUS {
+            return (iteration, full_norm);
+        }
+    }
+    return (0, 0.0);
+}
+
+struct StrideChunkJob {
+    y_start: usize,
+    y_end: usize,
+    width: usize,
+    height: usize,
+    view: ViewRect,
+    max_iterations: u16,
+    stride: usize,
+    reference: Option<Arc<PerturbationReference>>,
+}
+
+struct StrideChunkResult {
+    samples: Vec<(usize, usize, f64)>,
+    local_max: f64,
+}
+
+fn compute_stride_range(job: &StrideChunkJob) -> StrideChunkResult {
+    let width_delta = (job.view.x_max - job.view.x_min) / (job.width as f64);
+    let height_delta = (job.view.y_max - job.view.y_min) / (job.height as f64);
+
+    let mut samples: Vec<(usize, usize, f64)> = Vec::new();
+    let mut local_max: f64 = 0.0;
+
+    let mut height_interval = job.y_start;
+    while height_interval < job.y_end {
+        let mut width_interval = 0;
+        while width_interval < job.width {
+            let (iteration, escaped_norm) = match &job.reference {
+                // Pixel offset from the view centre, computed from index
+                // arithmetic rather than `x_min + pixel * delta`, so it
+                // stays accurate even once `x_min` and the pixel coordinate
+                // are too close together for f64 to tell apart.
+                Some(reference) => {
+                    let delta_x = ((width_interval as f64) - (job.width as f64 / 2.0)) * width_delta;
+                    let delta_y =
+                        ((height_interval as f64) - (job.height as f64 / 2.0)) * height_delta;
+                    calculate_instability_delta(Complex::new(delta_x, delta_y), reference)
+                }
+                None => {
+                    let x_pt = (job.view.x_min + (width_delta / 2.0))
+                        + (width_interval as f64 * width_delta);
+                    let y_pt = (job.view.y_min + (height_delta / 2.0))
+                        + (height_interval as f64 * height_delta);
+                    calculate_instability(Complex::new(x_pt, y_pt), job.max_iterations)
+                }
+            };
+            let mu = smooth_iteration_count(iteration, escaped_norm);
+            local_max = if mu > local_max { mu } else { local_max };
+            samples.push((width_interval, height_interval, mu));
+            width_interval += job.stride;
+        }
+        height_interval += job.stride;
+    }
+
+    return StrideChunkResult { samples, local_max };
+}
+
+fn generate_mandelbrot_pass(
     x_min: f64,
     x_max: f64,
     y_min: f64,
@@ -59,28 +287,229 @@ fn generate_mandelbrot(
     width: usize,
     height: usize,
     max_iterations: u16,
-) -> (Vec<u16>, u16) {
-    let mut grid: Vec<u16> = Vec::with_capacity(width * height);
-    let mut max_value: u16 = 0;
-
-    let width_delta = (x_max - x_min) / (width as f64);
-    let height_delta = (y_max - y_min) / (height as f64);
-    for height_interval in 0..height {
-        for width_interval in 0..width {
-            let x_pt = (x_min + (width_delta / 2.0)) + (width_interval as f64 * width_delta);
-            let y_pt = (y_min + (height_delta / 2.0)) + (height_interval as f64 * height_delta);
-            let c = Complex::new(x_pt, y_pt);
-            let instability = calculate_instability(c, max_iterations);
-            max_value = if instability > max_value {
-                instability
-            } else {
-                max_value
-            };
-            grid.push(instability);
+    stride: usize,
+    reference: Option<Arc<PerturbationReference>>,
+) -> (Vec<(usize, usize, f64)>, f64) {
+    let view = ViewRect {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    };
+
+    let chunk_height = CHUNK_HEIGHT * stride;
+    let worker_count = thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .min(height.max(1));
+
+    let (job_tx, job_rx) = mpsc::channel::<StrideChunkJob>();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (result_tx, result_rx) = mpsc::channel::<StrideChunkResult>();
+
+    let mut workers = Vec::with_capacity(worker_count);
+    for _ in 0..worker_count {
+        let job_rx = Arc::clone(&job_rx);
+        let result_tx = result_tx.clone();
+        workers.push(thread::spawn(move || loop {
+            let job = job_rx.lock().unwrap().recv();
+            match job {
+                Ok(job) => {
+                    let _ = result_tx.send(compute_stride_range(&job));
+                }
+                Err(_) => break,
+            }
+        }));
+    }
+    drop(result_tx);
+
+    let mut job_count = 0;
+    let mut y = 0;
+    while y < height {
+        let y_end = (y + chunk_height).min(height);
+        job_tx
+            .send(StrideChunkJob {
+                y_start: y,
+                y_end,
+                width,
+                height,
+                view,
+                max_iterations,
+                stride,
+                reference: reference.clone(),
+            })
+            .unwrap();
+        job_count += 1;
+        y = y_end;
+    }
+    drop(job_tx);
+
+    let mut samples: Vec<(usize, usize, f64)> = Vec::new();
+    let mut max_value: f64 = 0.0;
+    for _ in 0..job_count {
+        let result = result_rx.recv().unwrap();
+        samples.extend(result.samples);
+        max_value = if result.local_max > max_value {
+            result.local_max
+        } else {
+            max_value
+        };
+    }
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    return (samples, max_value);
+}
+
+fn paint_block_pass(
+    output: &mut Stdout,
+    samples: &[(usize, usize, f64)],
+    max_value: f64,
+    width: usize,
+    height: usize,
+    stride: usize,
+    back_buffer: &mut [Option<(u8, u8, u8)>],
+) {
+    for &(x, y, value) in samples {
+        let color_value = scale_color(value, max_value.max(1.0));
+        let block_width = stride.min(width - x);
+        let block_height = stride.min(height - y);
+
+        let is_clean = (0..block_height).all(|row| {
+            (0..block_width)
+                .all(|col| back_buffer[((y + row) * width) + (x + col)] == Some(color_value))
+        });
+        if is_clean {
+            continue;
+        }
+
+        let color = style::Color::Rgb {
+            r: color_value.0,
+            g: color_value.1,
+            b: color_value.2,
+        };
+        output.queue(style::SetBackgroundColor(color));
+        for row in 0..block_height {
+            output.queue(cursor::MoveTo(x as u16, (y + row) as u16));
+            output.queue(style::Print(" ".repeat(block_width)));
+            for col in 0..block_width {
+                back_buffer[((y + row) * width) + (x + col)] = Some(color_value);
+            }
         }
     }
+}
 
-    return (grid, max_value);
+fn paint_dense_pass(
+    output: &mut Stdout,
+    grid: &[f64],
+    max_value: f64,
+    width: usize,
+    height: usize,
+    back_buffer: &mut [Option<(u8, u8, u8)>],
+) {
+    for y in 0..height {
+        let mut x = 0;
+        while x < width {
+            let index = (y * width) + x;
+            let color_value = scale_color(grid[index], max_value.max(1.0));
+            if back_buffer[index] == Some(color_value) {
+                x += 1;
+                continue;
+            }
+
+            output.queue(cursor::MoveTo(x as u16, y as u16));
+            let mut prev_color = None;
+            while x < width {
+                let index = (y * width) + x;
+                let color_value = scale_color(grid[index], max_value.max(1.0));
+                if back_buffer[index] == Some(color_value) {
+                    break;
+                }
+
+                if prev_color != Some(color_value) {
+                    let color = style::Color::Rgb {
+                        r: color_value.0,
+                        g: color_value.1,
+                        b: color_value.2,
+                    };
+                    output.queue(style::SetBackgroundColor(color));
+                    prev_color = Some(color_value);
+                }
+                output.queue(style::Print(" "));
+                back_buffer[index] = Some(color_value);
+                x += 1;
+            }
+        }
+    }
+}
+
+const HALF_BLOCK_CHAR: char = '\u{2580}';
+
+// Half-block mode needs both the top and bottom sample of a cell pair at once
+// to print `HALF_BLOCK_CHAR`, so this painter is used only for the final
+// full-resolution pass; the coarser progressive passes before it are painted
+// as plain single-sample blocks by `paint_block_pass`.
+fn paint_dense_pass_half_block(
+    output: &mut Stdout,
+    grid: &[f64],
+    max_value: f64,
+    width: usize,
+    sample_height: usize,
+    back_buffer: &mut [Option<(u8, u8, u8)>],
+) {
+    let screen_height = sample_height / 2;
+
+    for screen_y in 0..screen_height {
+        let top_row = screen_y * 2;
+        let bottom_row = top_row + 1;
+
+        let mut x = 0;
+        while x < width {
+            let top_index = (top_row * width) + x;
+            let bottom_index = (bottom_row * width) + x;
+            let top_color = scale_color(grid[top_index], max_value.max(1.0));
+            let bottom_color = scale_color(grid[bottom_index], max_value.max(1.0));
+
+            if back_buffer[top_index] == Some(top_color) && back_buffer[bottom_index] == Some(bottom_color) {
+                x += 1;
+                continue;
+            }
+
+            output.queue(cursor::MoveTo(x as u16, screen_y as u16));
+            let mut prev_colors = None;
+            while x < width {
+                let top_index = (top_row * width) + x;
+                let bottom_index = (bottom_row * width) + x;
+                let top_color = scale_color(grid[top_index], max_value.max(1.0));
+                let bottom_color = scale_color(grid[bottom_index], max_value.max(1.0));
+
+                if back_buffer[top_index] == Some(top_color) && back_buffer[bottom_index] == Some(bottom_color)
+                {
+                    break;
+                }
+
+                if prev_colors != Some((top_color, bottom_color)) {
+                    output.queue(style::SetForegroundColor(style::Color::Rgb {
+                        r: top_color.0,
+                        g: top_color.1,
+                        b: top_color.2,
+                    }));
+                    output.queue(style::SetBackgroundColor(style::Color::Rgb {
+                        r: bottom_color.0,
+                        g: bottom_color.1,
+                        b: bottom_color.2,
+                    }));
+                    prev_colors = Some((top_color, bottom_color));
+                }
+                output.queue(style::Print(HALF_BLOCK_CHAR));
+                back_buffer[top_index] = Some(top_color);
+                back_buffer[bottom_index] = Some(bottom_color);
+                x += 1;
+            }
+        }
+    }
 }
 
 fn draw_mandelbrot(
@@ -92,43 +521,88 @@ fn draw_mandelbrot(
     width: usize,
     height: usize,
     max_iterations: u16,
-) -> () {
-    let (grid, max_value) =
-        generate_mandelbrot(x_min, x_max, y_min, y_max, width, height, max_iterations);
-    let mut x: usize = 0;
-    let mut y: usize = 0;
-
+    half_block: bool,
+    back_buffer: &mut [Option<(u8, u8, u8)>],
+) -> bool {
     output.queue(cursor::Hide);
 
-    let mut prev_value = 0;
+    // One reference orbit covers the whole frame regardless of stride or
+    // half-block mode, so it's computed once here and shared by every pass.
+    let reference = if (x_max - x_min) < DEEP_ZOOM_THRESHOLD {
+        Some(Arc::new(compute_reference_orbit(
+            Float::with_val(PERTURBATION_PRECISION_BITS, (x_min + x_max) / 2.0),
+            Float::with_val(PERTURBATION_PRECISION_BITS, (y_min + y_max) / 2.0),
+            max_iterations,
+        )))
+    } else {
+        None
+    };
 
-    for index in 0..grid.len() {
-        if index % width == 0 {
-            x = 0;
-            output.queue(cursor::MoveTo(x as u16, y as u16));
-            y += 1;
+    // Half-block's full-resolution pass needs two samples (top/bottom) per
+    // screen row, but the coarse previews below it are plain single-sample
+    // blocks either way, so only the final stride==1 pass differs between
+    // the two modes. Folding half-block into this same progressive loop
+    // (rather than a separate unpollable pass) gives it the same
+    // abort-on-new-input behaviour as every other render mode.
+    for &stride in PROGRESSIVE_STRIDES.iter() {
+        if stride == 1 && half_block {
+            let sample_height = height * 2;
+            let (samples, max_value) = generate_mandelbrot_pass(
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                width,
+                sample_height,
+                max_iterations,
+                1,
+                reference.clone(),
+            );
+            let mut grid: Vec<f64> = vec![0.0; width * sample_height];
+            for (x, y, value) in samples {
+                grid[(y * width) + x] = value;
+            }
+            paint_dense_pass_half_block(output, &grid, max_value, width, sample_height, back_buffer);
+        } else {
+            let (samples, max_value) = generate_mandelbrot_pass(
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                width,
+                height,
+                max_iterations,
+                stride,
+                reference.clone(),
+            );
+
+            if stride == 1 {
+                let mut grid: Vec<f64> = vec![0.0; width * height];
+                for (x, y, value) in samples {
+                    grid[(y * width) + x] = value;
+                }
+                paint_dense_pass(output, &grid, max_value, width, height, back_buffer);
+            } else {
+                paint_block_pass(output, &samples, max_value, width, height, stride, back_buffer);
+            }
         }
 
-        let value = grid[index];
+        output.queue(cursor::MoveTo(0, 0));
+        output.queue(style::ResetColor);
+        output.flush();
 
-        if index == 0 || value != prev_value {
-            prev_value = value;
-            let color_value = scale_color(value, max_value);
-            let color = style::Color::Rgb {
-                r: color_value.0,
-                g: color_value.1,
-                b: color_value.2,
-            };
-            output.queue(style::SetBackgroundColor(color));
+        // `event::poll` only peeks; the event that triggered this abort is
+        // still queued and will be consumed by `main()`'s next `event::read`,
+        // which may not be a key that sets `changed`. Report the abort so
+        // the caller can force a redraw regardless, guaranteeing the view
+        // eventually reaches the final stride instead of getting stuck on
+        // a coarse pass.
+        if event::poll(Duration::from_secs(0)).unwrap_or(false) {
+            return true;
         }
-
-        output.queue(style::Print(" "));
-        x += 1;
     }
 
-    output.queue(cursor::MoveTo(0, 0));
-    output.queue(style::ResetColor);
-    output.flush();
+    return false;
 }
 
 fn get_bounds(
@@ -138,6 +612,7 @@ fn get_bounds(
     y_size: f64,
     terminal_width: usize,
     terminal_height: usize,
+    half_block: bool,
 ) -> (f64, f64, f64, f64) {
     let x_size = x_size.abs();
     let y_size = y_size.abs();
@@ -148,9 +623,13 @@ fn get_bounds(
     let mut y_min = origin_y - (y_size * 0.5);
     let mut y_max = origin_y + (y_size * 0.5);
 
+    // Each cell covers two vertical samples in half-block mode, so the
+    // empirical cell-aspect fudge factor below is halved to match.
+    let y_aspect_divisor = if half_block { 2.5 / 2.0 } else { 2.5 };
+
     // TODO: fix this so that the ratio actually remains consistent
     let x_ratio = (x_max - x_min) / terminal_width as f64;
-    let y_ratio = ((y_max - y_min) / terminal_height as f64) / 2.5;
+    let y_ratio = ((y_max - y_min) / terminal_height as f64) / y_aspect_divisor;
 
     if x_ratio > y_ratio {
         let y_size = terminal_height as f64 * x_ratio;
@@ -193,6 +672,12 @@ fn print_help(output: &mut Stdout) {
     output.queue(style::Print(
         " c: See coords                                    \n",
     ));
+    output.queue(style::Print(
+        " h: Toggle half-block rendering                   \n",
+    ));
+    output.queue(style::Print(
+        " a: Toggle auto iterations                        \n",
+    ));
     output.queue(style::Print(
         " q: Quit program                                  \n",
     ));
@@ -265,6 +750,11 @@ fn main() {
 
     let mut bounds = (0.0, 0.0, 0.0, 0.0);
 
+    let mut half_block = false;
+    let mut auto_iterations = false;
+    let mut back_buffer: Vec<Option<(u8, u8, u8)>> =
+        vec![None; terminal_width * terminal_height * 2];
+
     let mut output = stdout();
 
     output.queue(terminal::EnterAlternateScreen);
@@ -300,6 +790,16 @@ fn main() {
                             show_coords = true;
                             changed = true;
                         }
+                        'h' => {
+                            half_block = !half_block;
+                            back_buffer = vec![None; terminal_width * terminal_height * 2];
+                            changed = true;
+                        }
+                        'a' => {
+                            auto_iterations = !auto_iterations;
+                            show_iterations = true;
+                            changed = true;
+                        }
                         'i' => {
                             if iterations >= 1000 {
                                 iterations += 1000;
@@ -367,6 +867,7 @@ fn main() {
                 event::Event::Resize(width, height) => {
                     terminal_width = width as usize - 1;
                     terminal_height = height as usize - 1;
+                    back_buffer = vec![None; terminal_width * terminal_height * 2];
                     changed = true;
                     show_help = true;
                 }
@@ -375,6 +876,12 @@ fn main() {
         }
 
         if changed {
+            let effective_iterations = if auto_iterations {
+                auto_max_iterations(x_size)
+            } else {
+                iterations
+            };
+
             bounds = get_bounds(
                 origin_x,
                 origin_y,
@@ -382,8 +889,9 @@ fn main() {
                 y_size,
                 terminal_width,
                 terminal_height,
+                half_block,
             );
-            draw_mandelbrot(
+            let aborted = draw_mandelbrot(
                 &mut output,
                 bounds.0,
                 bounds.1,
@@ -391,15 +899,21 @@ fn main() {
                 bounds.3,
                 terminal_width,
                 terminal_height,
-                iterations,
+                effective_iterations,
+                half_block,
+                &mut back_buffer,
             );
-            changed = false;
+            // An aborted pass may have been interrupted by an event that
+            // the event match at the top of the loop doesn't treat as view-changing (an unbound
+            // key, for instance), so force another pass rather than
+            // leaving the view stuck on a coarse stride.
+            changed = aborted;
 
             if show_help {
                 print_help(&mut output);
                 show_help = false;
             } else if show_iterations {
-                print_iterations(&mut output, iterations);
+                print_iterations(&mut output, effective_iterations);
                 show_iterations = false;
             } else if show_coords {
                 print_coordinates(&mut output, origin_x, origin_y, x_size, y_size);